@@ -22,11 +22,42 @@ const CONFIG_FILE: &str = "config.toml";
 #[derive(Deserialize, Serialize)]
 pub struct Config {
     pub listener: Option<SocketAddr>,
+    /// Optional address for the HTTP API surface (OpenAPI spec and docs).
+    pub api: Option<SocketAddr>,
+    pub tls: Option<Tls>,
+}
+
+/// TLS termination for the controller listener.
+///
+/// When `acme` is set the controller provisions and renews certificates
+/// itself via the ACME protocol; otherwise it loads the static `cert`/`key`
+/// pair from [`CONFIG_DIRECTORY`]. Leaving the whole section out keeps the
+/// listener on plain TCP, as before.
+#[derive(Deserialize, Serialize, Default, Clone)]
+pub struct Tls {
+    /// Path to the PEM certificate chain, relative to the config directory.
+    pub cert: Option<String>,
+    /// Path to the PEM private key, relative to the config directory.
+    pub key: Option<String>,
+    /// Automatically provision and renew certificates over ACME.
+    #[serde(default)]
+    pub acme: bool,
+    /// Contact e-mail registered with the ACME account.
+    pub contact: Option<String>,
+    /// Directory URL of the ACME provider (defaults to Let's Encrypt).
+    pub directory: Option<String>,
+    /// Domains the issued certificate should cover.
+    #[serde(default)]
+    pub domains: Vec<String>,
 }
 
 impl Config {
     fn new_empty() -> Self {
-        Self { listener: None }
+        Self {
+            listener: None,
+            api: None,
+            tls: None,
+        }
     }
 
     fn load_or_empty() -> Self {
@@ -0,0 +1,67 @@
+use std::process::exit;
+
+use log::{error, info};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_rustls::TlsAcceptor;
+
+use crate::config::Config;
+
+mod config;
+mod tls;
+
+#[tokio::main]
+async fn main() {
+    let config = Config::new_filled();
+    let address = config.listener.unwrap_or_else(|| {
+        error!("No listener address configured");
+        exit(1);
+    });
+
+    // Build the TLS acceptor (and spawn ACME renewal) when a [tls] section is
+    // present; otherwise stay on plain TCP.
+    let acceptor = tls::setup(&config).await.unwrap_or_else(|error| {
+        error!("Failed to set up TLS: {}", error);
+        exit(1);
+    });
+
+    // Serve the OpenAPI document and Swagger UI on the HTTP API surface when
+    // one is configured.
+    if let Some(api) = config.api {
+        tokio::spawn(async move {
+            if let Err(error) = controller::application::network::serve(api).await {
+                error!("HTTP API surface stopped: {}", error);
+            }
+        });
+    }
+
+    let listener = TcpListener::bind(address).await.unwrap_or_else(|error| {
+        error!("Failed to bind listener on {}: {}", address, error);
+        exit(1);
+    });
+    match &acceptor {
+        Some(_) => info!("Controller listening with TLS on {}", address),
+        None => info!("Controller listening on {}", address),
+    }
+
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move { serve(stream, acceptor).await });
+            }
+            Err(error) => error!("Failed to accept connection: {}", error),
+        }
+    }
+}
+
+/// Hand the accepted connection to the controller service, terminating TLS
+/// first when an acceptor is configured.
+async fn serve(stream: TcpStream, acceptor: Option<TlsAcceptor>) {
+    match acceptor {
+        Some(acceptor) => match acceptor.accept(stream).await {
+            Ok(stream) => controller::serve(stream).await,
+            Err(error) => error!("TLS handshake failed: {}", error),
+        },
+        None => controller::serve(stream).await,
+    }
+}
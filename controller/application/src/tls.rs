@@ -0,0 +1,300 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, bail, Context, Result};
+use instant_acme::{
+    Account, AuthorizationStatus, ChallengeType, Identifier, LetsEncrypt, NewAccount, NewOrder,
+    OrderStatus,
+};
+use log::{info, warn};
+use rcgen::{CertificateParams, DistinguishedName, KeyPair};
+use tokio::net::TcpListener;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::TlsAcceptor;
+use x509_parser::prelude::*;
+
+use crate::config::{Config, Tls, CONFIG_DIRECTORY};
+
+/// ACME account credentials, persisted so we reuse the same account.
+const ACCOUNT_FILE: &str = "acme-account.json";
+/// Issued certificate chain and its private key.
+const CERT_FILE: &str = "tls-cert.pem";
+const KEY_FILE: &str = "tls-key.pem";
+
+/// Renew once the certificate is within this window of expiring.
+const RENEW_WITHIN: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+/// How often the background task re-checks expiry.
+const CHECK_INTERVAL: Duration = Duration::from_secs(12 * 60 * 60);
+
+/// A loaded certificate chain and its private key, ready to feed the
+/// listener's TLS acceptor.
+pub struct Certificate {
+    pub chain: Vec<u8>,
+    pub key: Vec<u8>,
+}
+
+/// Build the TLS acceptor for the listener, if TLS is configured, and spawn
+/// the background renewal task when ACME is in use.
+///
+/// Returns `None` when the `[tls]` section is absent, leaving the caller on
+/// plain TCP.
+pub async fn setup(config: &Config) -> Result<Option<TlsAcceptor>> {
+    let Some(tls) = config.tls.as_ref() else {
+        return Ok(None);
+    };
+
+    let certificate = resolve(tls).await?;
+    let acceptor = acceptor(&certificate)?;
+
+    if tls.acme {
+        tokio::spawn(renew_periodically(tls.clone()));
+    }
+    Ok(Some(acceptor))
+}
+
+fn acceptor(certificate: &Certificate) -> Result<TlsAcceptor> {
+    let chain = rustls_pemfile::certs(&mut certificate.chain.as_slice())
+        .collect::<std::result::Result<Vec<CertificateDer>, _>>()?;
+    let key = rustls_pemfile::private_key(&mut certificate.key.as_slice())?
+        .ok_or_else(|| anyhow!("No private key found in the configured TLS key"))?;
+    let config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(chain, PrivateKeyDer::from(key))?;
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+/// Resolve the TLS material for the listener, provisioning over ACME when the
+/// configuration asks for it and no valid certificate is on disk yet.
+pub async fn resolve(tls: &Tls) -> Result<Certificate> {
+    if tls.acme {
+        provision_or_load(tls).await
+    } else {
+        load_static(tls)
+    }
+}
+
+fn config_path(name: &str) -> PathBuf {
+    Path::new(CONFIG_DIRECTORY).join(name)
+}
+
+fn load_static(tls: &Tls) -> Result<Certificate> {
+    let cert = tls
+        .cert
+        .as_ref()
+        .ok_or_else(|| anyhow!("No certificate path configured in the [tls] section"))?;
+    let key = tls
+        .key
+        .as_ref()
+        .ok_or_else(|| anyhow!("No key path configured in the [tls] section"))?;
+    Ok(Certificate {
+        chain: std::fs::read(config_path(cert))?,
+        key: std::fs::read(config_path(key))?,
+    })
+}
+
+async fn provision_or_load(tls: &Tls) -> Result<Certificate> {
+    let cert_path = config_path(CERT_FILE);
+    let key_path = config_path(KEY_FILE);
+    if cert_path.exists() && key_path.exists() && !needs_renewal(&cert_path)? {
+        return Ok(Certificate {
+            chain: std::fs::read(&cert_path)?,
+            key: std::fs::read(&key_path)?,
+        });
+    }
+    issue(tls).await
+}
+
+/// Whether the certificate at `path` expires within [`RENEW_WITHIN`].
+fn needs_renewal(path: &Path) -> Result<bool> {
+    let not_after = certificate_expiry(path)?;
+    let renew_at = not_after
+        .checked_sub(RENEW_WITHIN)
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+    Ok(SystemTime::now() >= renew_at)
+}
+
+/// Run a full ACME order (RFC 8555): load or create the account, place an
+/// order for the configured domains, answer the HTTP-01 challenges from a
+/// temporary responder on port 80, finalize, and persist the issued chain.
+async fn issue(tls: &Tls) -> Result<Certificate> {
+    if tls.domains.is_empty() {
+        bail!("ACME is enabled but no domains are configured");
+    }
+    let directory = tls
+        .directory
+        .clone()
+        .unwrap_or_else(|| LetsEncrypt::Production.url().to_string());
+    info!("Provisioning a certificate for {} via {}", tls.domains.join(", "), directory);
+
+    let account = load_or_create_account(tls, &directory).await?;
+
+    let identifiers = tls
+        .domains
+        .iter()
+        .map(|domain| Identifier::Dns(domain.clone()))
+        .collect::<Vec<_>>();
+    let mut order = account
+        .new_order(&NewOrder {
+            identifiers: &identifiers,
+        })
+        .await?;
+
+    // Collect every HTTP-01 challenge, serve them all from a single responder,
+    // mark them ready, wait for validation, then shut the responder down.
+    let authorizations = order.authorizations().await?;
+    let mut tokens = std::collections::HashMap::new();
+    let mut challenge_urls = Vec::new();
+    for authz in &authorizations {
+        match authz.status {
+            AuthorizationStatus::Valid => continue,
+            AuthorizationStatus::Pending => {}
+            status => bail!("Unexpected authorization status: {:?}", status),
+        }
+        let challenge = authz
+            .challenges
+            .iter()
+            .find(|challenge| challenge.r#type == ChallengeType::Http01)
+            .ok_or_else(|| anyhow!("No HTTP-01 challenge offered"))?;
+        let key_auth = order.key_authorization(challenge);
+        tokens.insert(challenge.token.clone(), key_auth.as_str().to_string());
+        challenge_urls.push(challenge.url.clone());
+    }
+
+    let responder = serve_http01(tokens).await?;
+    let mut validated: Result<()> = Ok(());
+    for url in &challenge_urls {
+        if let Err(error) = order.set_challenge_ready(url).await {
+            validated = Err(error.into());
+            break;
+        }
+    }
+    if validated.is_ok() {
+        validated = poll_until_ready(&mut order).await;
+    }
+    responder.abort();
+    validated?;
+
+    // Generate the key pair + CSR and finalize the order.
+    let mut params = CertificateParams::new(tls.domains.clone())?;
+    params.distinguished_name = DistinguishedName::new();
+    let key_pair = KeyPair::generate()?;
+    let csr = params.serialize_request(&key_pair)?;
+    order.finalize(csr.der()).await?;
+
+    let chain = loop {
+        match order.certificate().await? {
+            Some(chain) => break chain,
+            None => tokio::time::sleep(Duration::from_secs(1)).await,
+        }
+    };
+
+    let certificate = Certificate {
+        chain: chain.into_bytes(),
+        key: key_pair.serialize_pem().into_bytes(),
+    };
+    std::fs::write(config_path(CERT_FILE), &certificate.chain)?;
+    std::fs::write(config_path(KEY_FILE), &certificate.key)?;
+    Ok(certificate)
+}
+
+async fn load_or_create_account(tls: &Tls, directory: &str) -> Result<Account> {
+    let path = config_path(ACCOUNT_FILE);
+    if path.exists() {
+        let credentials = serde_json::from_slice(&std::fs::read(&path)?)
+            .context("Failed to parse the persisted ACME account")?;
+        return Ok(Account::from_credentials(credentials).await?);
+    }
+
+    let contact = tls
+        .contact
+        .as_ref()
+        .map(|mail| format!("mailto:{mail}"))
+        .map(|mail| vec![mail])
+        .unwrap_or_default();
+    let (account, credentials) = Account::create(
+        &NewAccount {
+            contact: &contact.iter().map(String::as_str).collect::<Vec<_>>(),
+            terms_of_service_agreed: true,
+            only_return_existing: false,
+        },
+        directory,
+        None,
+    )
+    .await?;
+    std::fs::write(path, serde_json::to_vec(&credentials)?)?;
+    Ok(account)
+}
+
+/// Spawn a single long-lived HTTP-01 responder on port 80 answering every
+/// well-known challenge path in `tokens` (token → key authorization). The
+/// caller aborts the returned task once validation is complete.
+async fn serve_http01(
+    tokens: std::collections::HashMap<String, String>,
+) -> Result<tokio::task::JoinHandle<()>> {
+    let listener = TcpListener::bind(("0.0.0.0", 80)).await?;
+    Ok(tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+            let mut buffer = [0u8; 1024];
+            let read = stream.read(&mut buffer).await.unwrap_or(0);
+            let request = String::from_utf8_lossy(&buffer[..read]);
+            let body = tokens
+                .iter()
+                .find(|(token, _)| request.contains(&format!("/.well-known/acme-challenge/{token}")))
+                .map(|(_, key_auth)| key_auth.as_str())
+                .unwrap_or("");
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    }))
+}
+
+async fn poll_until_ready(order: &mut instant_acme::Order) -> Result<()> {
+    let mut attempts = 0;
+    loop {
+        let state = order.refresh().await?;
+        match state.status {
+            OrderStatus::Ready | OrderStatus::Valid => return Ok(()),
+            OrderStatus::Invalid => bail!("ACME order became invalid"),
+            _ if attempts >= 10 => bail!("ACME order did not become ready in time"),
+            _ => {
+                attempts += 1;
+                tokio::time::sleep(Duration::from_secs(2)).await;
+            }
+        }
+    }
+}
+
+/// Background task: periodically re-check the issued certificate and renew it
+/// once it falls inside the renewal window.
+pub async fn renew_periodically(tls: Tls) {
+    loop {
+        tokio::time::sleep(CHECK_INTERVAL).await;
+        match needs_renewal(&config_path(CERT_FILE)) {
+            Ok(true) => {
+                info!("Certificate is within the renewal window, renewing");
+                if let Err(error) = issue(&tls).await {
+                    warn!("Failed to renew certificate: {}", error);
+                }
+            }
+            Ok(false) => {}
+            Err(error) => warn!("Failed to inspect certificate expiry: {}", error),
+        }
+    }
+}
+
+/// Read the `notAfter` timestamp out of a PEM certificate on disk.
+fn certificate_expiry(path: &Path) -> Result<SystemTime> {
+    let pem = std::fs::read(path)?;
+    let (_, parsed) = parse_x509_pem(&pem).map_err(|error| anyhow!("Invalid certificate: {error}"))?;
+    let (_, cert) = parse_x509_certificate(&parsed.contents)
+        .map_err(|error| anyhow!("Invalid certificate: {error}"))?;
+    let not_after = cert.validity().not_after.timestamp();
+    Ok(UNIX_EPOCH + Duration::from_secs(not_after.max(0) as u64))
+}
@@ -0,0 +1,3 @@
+pub mod backend;
+pub mod deployment;
+pub mod docker;
@@ -1,4 +1,6 @@
 use std::path::Path;
+use std::thread::sleep;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use allocation::BAllocation;
 use anyhow::Result;
@@ -17,6 +19,9 @@ use crate::{
     warn,
 };
 
+use super::deployment::{DeploymentBackend, Driver};
+use super::docker::Docker;
+
 pub mod allocation;
 mod common;
 mod node;
@@ -28,6 +33,11 @@ const BACKEND_FILE: &str = "backend.toml";
 /* Endpoints */
 const APPLICATION_ENDPOINT: &str = "/api/application";
 
+/* Retry defaults (used when the values are absent from backend.toml) */
+const DEFAULT_RETRIES: u32 = 3;
+const DEFAULT_BASE_DELAY_MS: u64 = 500;
+const DEFAULT_MAX_DELAY_MS: u64 = 30_000;
+
 #[derive(Deserialize, Serialize)]
 pub struct ResolvedValues {
     pub user: u32,
@@ -35,9 +45,14 @@ pub struct ResolvedValues {
 
 #[derive(Deserialize, Serialize)]
 pub struct Backend {
+    driver: Option<Driver>,
     url: Option<String>,
     token: Option<String>,
     user: Option<String>,
+    socket: Option<String>,
+    retries: Option<u32>,
+    base_delay_ms: Option<u64>,
+    max_delay_ms: Option<u64>,
     resolved: Option<ResolvedValues>,
 }
 
@@ -59,9 +74,14 @@ impl ResolvedValues {
 impl Backend {
     fn new_empty() -> Self {
         Self {
+            driver: Some(Driver::default()),
             url: Some("".to_string()),
             token: Some("".to_string()),
             user: Some("".to_string()),
+            socket: None,
+            retries: Some(DEFAULT_RETRIES),
+            base_delay_ms: Some(DEFAULT_BASE_DELAY_MS),
+            max_delay_ms: Some(DEFAULT_MAX_DELAY_MS),
             resolved: None,
         }
     }
@@ -120,6 +140,17 @@ impl Backend {
         Ok(backend)
     }
 
+    /// Construct the deployment backend selected by `driver` in
+    /// `backend.toml`, boxed behind [`DeploymentBackend`] so the rest of the
+    /// crate is agnostic to which driver is in use.
+    pub fn selected() -> Result<Box<dyn DeploymentBackend>> {
+        let backend = Self::load_or_empty();
+        match backend.driver.unwrap_or_default() {
+            Driver::Pterodactyl => Ok(Box::new(Self::new_filled_and_resolved()?)),
+            Driver::Docker => Ok(Box::new(Docker::new(backend.socket))),
+        }
+    }
+
     pub fn new_filled_and_resolved() -> Result<Self> {
         let mut backend = Self::new_filled()?;
         match ResolvedValues::new_resolved(&backend) {
@@ -303,22 +334,143 @@ impl Backend {
             "Sending request to the pterodactyl panel: {:?} {}",
             method, &url
         );
-        let response = send_http_request(
-            method,
-            &url,
-            &[Header {
-                key: "Authorization".to_string(),
-                value: format!("Bearer {}", &self.token.as_ref().unwrap()),
-            }],
-            body,
-        );
+        let response = self.send_with_retry(method, &url, body);
         if let Some(response) = Self::handle_response::<T>(response, 200) {
             return Some(response);
         }
         None
     }
 
-    fn handle_response<T: DeserializeOwned>(
+    /// Perform the HTTP call, transparently retrying transient failures.
+    ///
+    /// A `429` (rate limited) or any `5xx` is treated as retryable and is
+    /// reattempted up to `retries` times with exponential backoff and jitter.
+    /// A `Retry-After` header overrides the computed delay, and the
+    /// `X-RateLimit-Remaining`/`X-RateLimit-Reset` pair is used to pause
+    /// proactively before the panel rejects us.
+    fn send_with_retry(&self, method: Method, url: &str, body: Option<&[u8]>) -> Option<Response> {
+        let headers = [Header {
+            key: "Authorization".to_string(),
+            value: format!("Bearer {}", &self.token.as_ref().unwrap()),
+        }];
+        let retries = self.retries.unwrap_or(DEFAULT_RETRIES);
+        let base = self.base_delay_ms.unwrap_or(DEFAULT_BASE_DELAY_MS);
+        let max = self.max_delay_ms.unwrap_or(DEFAULT_MAX_DELAY_MS);
+
+        let mut attempt = 0;
+        loop {
+            match send_http_request(method, url, &headers, body) {
+                Some(response)
+                    if response.status_code == 429 || (500..600).contains(&response.status_code) =>
+                {
+                    if attempt >= retries {
+                        warn!(
+                            "Giving up on {} after {} retries (last status {})",
+                            url, retries, &response.status_code
+                        );
+                        return Some(response);
+                    }
+                    let delay = Self::retry_delay(&response, attempt, base, max);
+                    warn!(
+                        "Pterodactyl panel returned {}, retrying in {}ms (attempt {}/{})",
+                        &response.status_code,
+                        delay,
+                        attempt + 1,
+                        retries
+                    );
+                    sleep(Duration::from_millis(delay));
+                    attempt += 1;
+                }
+                Some(response) => {
+                    Self::pause_for_rate_limit(&response, max);
+                    return Some(response);
+                }
+                None => {
+                    if attempt >= retries {
+                        return None;
+                    }
+                    let delay = Self::backoff_delay(attempt, base, max);
+                    warn!(
+                        "Request to {} failed, retrying in {}ms (attempt {}/{})",
+                        url,
+                        delay,
+                        attempt + 1,
+                        retries
+                    );
+                    sleep(Duration::from_millis(delay));
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Delay before the next retry: a literal `Retry-After` if present,
+    /// otherwise the computed exponential backoff.
+    fn retry_delay(response: &Response, attempt: u32, base: u64, max: u64) -> u64 {
+        if let Some(retry_after) = Self::header_value(response, "Retry-After") {
+            if let Ok(seconds) = retry_after.trim().parse::<u64>() {
+                return seconds.saturating_mul(1000);
+            }
+        }
+        Self::backoff_delay(attempt, base, max)
+    }
+
+    /// `base * 2^attempt`, capped at `max`, with up to ±50% jitter.
+    fn backoff_delay(attempt: u32, base: u64, max: u64) -> u64 {
+        let delay = base.saturating_mul(1u64 << attempt.min(16)).min(max);
+        Self::jitter(delay)
+    }
+
+    /// Honor the rate-limit headers proactively: if the panel reports that
+    /// no requests remain in the current window, sleep until it resets.
+    ///
+    /// Pterodactyl (Laravel) emits `X-RateLimit-Reset` as an absolute UNIX
+    /// timestamp, so we sleep for the remaining delta rather than the value
+    /// itself, capped at `max_ms` to guard against a clock far in the future.
+    fn pause_for_rate_limit(response: &Response, max_ms: u64) {
+        let remaining = Self::header_value(response, "X-RateLimit-Remaining")
+            .and_then(|value| value.trim().parse::<u32>().ok());
+        if remaining == Some(0) {
+            if let Some(reset) = Self::header_value(response, "X-RateLimit-Reset")
+                .and_then(|value| value.trim().parse::<u64>().ok())
+            {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|elapsed| elapsed.as_secs())
+                    .unwrap_or(0);
+                let delay_ms = reset.saturating_sub(now).saturating_mul(1000).min(max_ms);
+                if delay_ms > 0 {
+                    debug!("Rate limit exhausted, pausing for {}ms before continuing", delay_ms);
+                    sleep(Duration::from_millis(delay_ms));
+                }
+            }
+        }
+    }
+
+    fn header_value<'a>(response: &'a Response, key: &str) -> Option<&'a str> {
+        response
+            .headers
+            .iter()
+            .find(|header| header.key.eq_ignore_ascii_case(key))
+            .map(|header| header.value.as_str())
+    }
+
+    /// Spread retries out by ±50% using the process clock, avoiding a
+    /// dedicated random-number dependency.
+    fn jitter(delay: u64) -> u64 {
+        let half = delay / 2;
+        if half == 0 {
+            return delay;
+        }
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.subsec_nanos() as u64)
+            .unwrap_or(0);
+        let offset = nanos % (half * 2 + 1);
+        (delay + offset).saturating_sub(half)
+    }
+
+    pub(crate) fn handle_response<T: DeserializeOwned>(
         response: Option<Response>,
         expected_code: u32,
     ) -> Option<T> {
@@ -350,5 +502,31 @@ impl Backend {
     }
 }
 
+impl super::deployment::DeploymentBackend for Backend {
+    fn create_server(
+        &self,
+        server: &Server,
+        allocation: &BAllocation,
+        egg: u32,
+        startup: &str,
+        features: BServerFeatureLimits,
+    ) -> Option<BServer> {
+        Backend::create_server(self, server, allocation, egg, startup, features)
+    }
+
+    fn get_server_by_name(&self, name: &str) -> Option<BServer> {
+        Backend::get_server_by_name(self, name)
+    }
+
+    fn get_free_allocations(
+        &self,
+        used_allocations: &[BAllocation],
+        node_id: u32,
+        amount: u32,
+    ) -> Vec<BAllocation> {
+        Backend::get_free_allocations(self, used_allocations, node_id, amount)
+    }
+}
+
 impl SaveToTomlFile for Backend {}
 impl LoadFromTomlFile for Backend {}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::exports::node::driver::bridge::{Resources, Server};
+
+use super::allocation::BAllocation;
+
+/// A server as returned by the panel (the `attributes` of a server object).
+#[derive(Deserialize, Serialize, Clone)]
+pub struct BServer {
+    #[serde(default)]
+    pub id: u32,
+    pub name: String,
+    /// Backend-specific identifier (the Docker container id for the Docker
+    /// driver, the panel identifier otherwise).
+    #[serde(default)]
+    pub identifier: String,
+}
+
+/// Resource limits sent to the panel when creating a server.
+#[derive(Deserialize, Serialize, Clone, Copy)]
+pub struct BServerLimits {
+    pub memory: u32,
+    pub swap: i32,
+    pub disk: u32,
+    pub io: u32,
+    pub cpu: u32,
+}
+
+/// Feature limits (databases/allocations/backups) for a created server.
+#[derive(Deserialize, Serialize, Clone, Copy)]
+pub struct BServerFeatureLimits {
+    pub databases: u32,
+    pub allocations: u32,
+    pub backups: u32,
+}
+
+/// Body used to create a server.
+#[derive(Serialize)]
+pub struct BCServer {
+    pub name: String,
+    pub user: u32,
+    pub egg: u32,
+    pub docker_image: String,
+    pub startup: String,
+    pub environment: HashMap<String, String>,
+    pub limits: BServerLimits,
+    pub feature_limits: BServerFeatureLimits,
+    pub allocation: BCServerAllocation,
+}
+
+/// Default allocation reference in a create-server body.
+#[derive(Serialize)]
+pub struct BCServerAllocation {
+    pub default: u32,
+}
+
+impl From<Resources> for BServerLimits {
+    fn from(resources: Resources) -> Self {
+        Self {
+            memory: resources.memory,
+            swap: 0,
+            disk: 0,
+            io: 500,
+            cpu: resources.cpu,
+        }
+    }
+}
+
+impl BServer {
+    /// Build the backend view of a freshly created Docker container.
+    pub fn from_docker(container_id: String, server: &Server, allocation: &BAllocation) -> Self {
+        Self {
+            id: allocation.id,
+            name: server.name.clone(),
+            identifier: container_id,
+        }
+    }
+
+    /// Build the backend view of a Docker container found by name.
+    pub fn from_docker_list(container_id: String, name: &str) -> Self {
+        Self {
+            id: 0,
+            name: name.to_string(),
+            identifier: container_id,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::exports::node::driver::bridge::Server;
+
+use super::backend::allocation::BAllocation;
+use super::backend::server::{BServer, BServerFeatureLimits};
+
+/// Which deployment driver the controller should talk to.
+///
+/// Selected through the `driver` field in `backend.toml`; defaults to
+/// [`Driver::Pterodactyl`] so existing configurations keep working.
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Driver {
+    #[default]
+    Pterodactyl,
+    Docker,
+}
+
+/// The set of operations the rest of the crate needs from whatever actually
+/// spawns servers, so Pterodactyl is no longer the only option.
+///
+/// Implementors translate a [`Server`] plus its resource limits into their
+/// native representation and surface everything back through the
+/// [`BAllocation`]/[`BServer`] model the controller already understands.
+pub trait DeploymentBackend {
+    /// Create a new server bound to `allocation` and return its backend view.
+    fn create_server(
+        &self,
+        server: &Server,
+        allocation: &BAllocation,
+        egg: u32,
+        startup: &str,
+        features: BServerFeatureLimits,
+    ) -> Option<BServer>;
+
+    /// Look an existing server up by its name.
+    fn get_server_by_name(&self, name: &str) -> Option<BServer>;
+
+    /// Discover up to `amount` allocations that are not yet in use.
+    fn get_free_allocations(
+        &self,
+        used_allocations: &[BAllocation],
+        node_id: u32,
+        amount: u32,
+    ) -> Vec<BAllocation>;
+}
@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+
+use serde::{Deserialize, Serialize};
+
+use crate::exports::node::driver::bridge::{Resources, Server};
+use crate::node::driver::http::{Method, Response};
+use crate::{debug, error};
+
+use super::backend::allocation::BAllocation;
+use super::backend::server::{BServer, BServerFeatureLimits};
+use super::deployment::DeploymentBackend;
+
+/// Default location of the Docker Engine control socket.
+const DEFAULT_SOCKET: &str = "/var/run/docker.sock";
+/// Docker Engine API version this driver targets.
+const API_VERSION: &str = "v1.43";
+/// Host interface containers publish their ports on.
+const PUBLISH_IP: &str = "0.0.0.0";
+
+/// Talks to the Docker Engine directly over its unix socket, letting
+/// atomic-cloud spawn servers as plain containers without a panel.
+#[derive(Deserialize, Serialize)]
+pub struct Docker {
+    socket: Option<String>,
+}
+
+/* Container-create request (subset of the Engine `ContainerCreate` body) */
+
+#[derive(Serialize)]
+struct DCreate {
+    #[serde(rename = "Image")]
+    image: String,
+    #[serde(rename = "Env")]
+    env: Vec<String>,
+    #[serde(rename = "ExposedPorts")]
+    exposed_ports: HashMap<String, EmptyObject>,
+    #[serde(rename = "HostConfig")]
+    host_config: DHostConfig,
+}
+
+#[derive(Serialize)]
+struct DHostConfig {
+    #[serde(rename = "Memory")]
+    memory: u64,
+    #[serde(rename = "NanoCpus")]
+    nano_cpus: u64,
+    #[serde(rename = "PortBindings")]
+    port_bindings: HashMap<String, Vec<DPortBinding>>,
+}
+
+#[derive(Serialize)]
+struct DPortBinding {
+    #[serde(rename = "HostIp")]
+    host_ip: String,
+    #[serde(rename = "HostPort")]
+    host_port: String,
+}
+
+#[derive(Serialize)]
+struct EmptyObject {}
+
+#[derive(Deserialize)]
+struct DCreateResponse {
+    #[serde(rename = "Id")]
+    id: String,
+}
+
+impl From<&Resources> for DHostConfig {
+    fn from(resources: &Resources) -> Self {
+        Self {
+            // The bridge expresses memory in MiB and cpu as hundredths of a
+            // core; Docker wants bytes and nano-cpus respectively.
+            memory: resources.memory as u64 * 1024 * 1024,
+            nano_cpus: resources.cpu as u64 * 10_000_000,
+            port_bindings: HashMap::new(),
+        }
+    }
+}
+
+impl Docker {
+    pub fn new(socket: Option<String>) -> Self {
+        Self { socket }
+    }
+
+    fn socket_path(&self) -> &str {
+        self.socket.as_deref().unwrap_or(DEFAULT_SOCKET)
+    }
+
+    /// Perform an HTTP request against the Docker Engine over its unix socket,
+    /// mirroring [`send_http_request`](crate::node::driver::http::send_http_request)
+    /// but speaking to a local socket instead of a TCP endpoint.
+    fn send_socket_request(&self, method: Method, path: &str, body: Option<&[u8]>) -> Option<Response> {
+        let mut stream = UnixStream::connect(self.socket_path())
+            .map_err(|error| error!("Failed to connect to the Docker socket: {}", error))
+            .ok()?;
+
+        let verb = match method {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        };
+        let body = body.unwrap_or(&[]);
+        let request = format!(
+            "{} /{}/{} HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            verb,
+            API_VERSION,
+            path,
+            body.len()
+        );
+        debug!("Sending request to the Docker Engine: {} {}", verb, path);
+        stream
+            .write_all(request.as_bytes())
+            .and_then(|()| stream.write_all(body))
+            .map_err(|error| error!("Failed to write to the Docker socket: {}", error))
+            .ok()?;
+
+        let mut raw = Vec::new();
+        stream
+            .read_to_end(&mut raw)
+            .map_err(|error| error!("Failed to read from the Docker socket: {}", error))
+            .ok()?;
+        Self::parse_response(&raw)
+    }
+
+    /// Parse a raw HTTP/1.1 response off the socket into the crate's
+    /// [`Response`] model. Chunked transfer encoding is not expected for the
+    /// small JSON bodies the Engine returns here.
+    fn parse_response(raw: &[u8]) -> Option<Response> {
+        let split = raw.windows(4).position(|window| window == b"\r\n\r\n")?;
+        let head = String::from_utf8_lossy(&raw[..split]);
+        let mut lines = head.lines();
+
+        let status_line = lines.next()?;
+        let mut parts = status_line.split_whitespace();
+        let _version = parts.next()?;
+        let status_code = parts.next()?.parse::<u32>().ok()?;
+        let reason_phrase = parts.collect::<Vec<_>>().join(" ");
+
+        let headers = lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| crate::node::driver::http::Header {
+                key: key.trim().to_string(),
+                value: value.trim().to_string(),
+            })
+            .collect();
+
+        Some(Response {
+            status_code,
+            reason_phrase,
+            headers,
+            bytes: raw[split + 4..].to_vec(),
+        })
+    }
+}
+
+impl DeploymentBackend for Docker {
+    fn create_server(
+        &self,
+        server: &Server,
+        allocation: &BAllocation,
+        _egg: u32,
+        _startup: &str,
+        _features: BServerFeatureLimits,
+    ) -> Option<BServer> {
+        let port = format!("{}/tcp", allocation.port);
+        let mut host_config = DHostConfig::from(&server.allocation.resources);
+        host_config.port_bindings.insert(
+            port.clone(),
+            vec![DPortBinding {
+                host_ip: PUBLISH_IP.to_string(),
+                host_port: allocation.port.to_string(),
+            }],
+        );
+        let mut exposed_ports = HashMap::new();
+        exposed_ports.insert(port, EmptyObject {});
+
+        let request = DCreate {
+            image: server.allocation.deployment.image.clone(),
+            env: server
+                .allocation
+                .deployment
+                .environment
+                .iter()
+                .map(|value| format!("{}={}", value.key, value.value))
+                .collect(),
+            exposed_ports,
+            host_config,
+        };
+
+        let body = serde_json::to_vec(&request).ok()?;
+        let path = format!("containers/create?name={}", server.name);
+        let response = self.send_socket_request(Method::Post, &path, Some(&body));
+        let created: DCreateResponse = super::backend::Backend::handle_response(response, 201)?;
+
+        // Start the container now that it has been created.
+        self.send_socket_request(
+            Method::Post,
+            &format!("containers/{}/start", created.id),
+            None,
+        );
+
+        Some(BServer::from_docker(created.id, server, allocation))
+    }
+
+    fn get_server_by_name(&self, name: &str) -> Option<BServer> {
+        // Docker matches container names with a leading slash. Build the
+        // filter with serde so a name containing quotes or backslashes can't
+        // produce malformed or injected JSON.
+        let filters = serde_json::json!({ "name": [format!("/{}", name)] }).to_string();
+        let path = format!("containers/json?all=true&filters={}", url_encode(&filters));
+        let response = self.send_socket_request(Method::Get, &path, None);
+        let containers: Vec<DContainer> = super::backend::Backend::handle_response(response, 200)?;
+        containers
+            .into_iter()
+            .next()
+            .map(|container| BServer::from_docker_list(container.id, name))
+    }
+
+    fn get_free_allocations(
+        &self,
+        used_allocations: &[BAllocation],
+        _node_id: u32,
+        amount: u32,
+    ) -> Vec<BAllocation> {
+        // Without a panel there is no allocation registry; hand out host ports
+        // from an ephemeral range, skipping anything already in use.
+        const RANGE_START: u16 = 30_000;
+        let mut allocations = Vec::with_capacity(amount as usize);
+        let mut port = RANGE_START;
+        while allocations.len() < amount as usize && port < u16::MAX {
+            let taken = used_allocations.iter().any(|used| used.port == port);
+            if !taken {
+                allocations.push(BAllocation {
+                    id: port as u32,
+                    ip: PUBLISH_IP.to_string(),
+                    port,
+                    assigned: false,
+                });
+            }
+            port += 1;
+        }
+        allocations
+    }
+}
+
+#[derive(Deserialize)]
+struct DContainer {
+    #[serde(rename = "Id")]
+    id: String,
+}
+
+/// Percent-encode a query-string value so the JSON `filters` parameter (with
+/// its `{`, `}`, `"` characters) is transmitted as valid URL octets.
+fn url_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char);
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
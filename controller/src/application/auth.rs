@@ -0,0 +1,31 @@
+use server::AuthServer;
+use user::AdminUser;
+
+pub mod commands;
+pub mod server;
+pub mod user;
+
+/// A boxed authorization, regardless of whether it backs a user or a server.
+pub type OwnedAuthorization = Box<dyn GenericAuthorization>;
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum AuthType {
+    User,
+    Server,
+}
+
+/// Common behaviour shared by every authorized party.
+pub trait GenericAuthorization {
+    fn is_allowed(&self, flag: u32) -> bool;
+    fn get_user(&self) -> Option<&AdminUser>;
+    fn get_server(&self) -> Option<&AuthServer>;
+    fn is_type(&self, auth: AuthType) -> bool;
+    fn recreate(&self) -> OwnedAuthorization;
+}
+
+/// Resolve a connecting client's credentials into an authorization by
+/// consulting the persisted user store. Returns `None` for an unknown user,
+/// which the acceptance path treats as a rejected connection.
+pub fn authenticate(username: &str) -> Option<OwnedAuthorization> {
+    user::authorize(username)
+}
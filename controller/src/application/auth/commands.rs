@@ -0,0 +1,40 @@
+use anyhow::Result;
+
+use super::user::{flags, StoredUser, UserStore};
+
+/// Administrative operations over the persisted user store, exposed to the
+/// controller's admin command surface.
+///
+/// These mirror the panel-style `list-users` / `create-user` /
+/// `add-invitation` operations: an admin mints an invitation carrying a set
+/// of permission flags, and a new client redeems it to register itself.
+
+/// List every registered user.
+pub fn list_users() -> Vec<StoredUser> {
+    UserStore::load().list_users().to_vec()
+}
+
+/// Create a user with an explicit permission mask.
+pub fn create_user(username: String, mask: u32) -> Result<()> {
+    UserStore::load().create_user(username, mask)
+}
+
+/// Revoke a user, removing them from the store.
+pub fn revoke_user(username: &str) -> Result<()> {
+    UserStore::load().revoke_user(username)
+}
+
+/// Mint a single-use invitation granting `mask`, returning its token.
+pub fn add_invitation(mask: u32) -> Result<String> {
+    UserStore::load().add_invitation(mask)
+}
+
+/// Redeem an invitation, registering `username` with the invitation's flags.
+pub fn redeem_invitation(token: &str, username: String) -> Result<()> {
+    UserStore::load().redeem_invitation(token, username)
+}
+
+/// Mint an invitation granting full access (a new administrator).
+pub fn invite_admin() -> Result<String> {
+    add_invitation(flags::ALL)
+}
@@ -0,0 +1,37 @@
+use getset::Getters;
+
+use super::{user::AdminUser, AuthType, GenericAuthorization, OwnedAuthorization};
+
+/// A server authenticated by its unit identifier. Servers hold no permission
+/// flags of their own, so `is_allowed` is always false.
+#[derive(Getters)]
+pub struct AuthServer {
+    #[getset(get = "pub")]
+    identifier: String,
+}
+
+impl GenericAuthorization for AuthServer {
+    fn is_allowed(&self, _flag: u32) -> bool {
+        false
+    }
+
+    fn get_user(&self) -> Option<&AdminUser> {
+        None
+    }
+    fn get_server(&self) -> Option<&AuthServer> {
+        Some(self)
+    }
+    fn is_type(&self, auth: AuthType) -> bool {
+        auth == AuthType::Server
+    }
+
+    fn recreate(&self) -> OwnedAuthorization {
+        AuthServer::create(self.identifier.clone())
+    }
+}
+
+impl AuthServer {
+    pub fn create(identifier: String) -> OwnedAuthorization {
+        Box::new(Self { identifier })
+    }
+}
@@ -1,7 +1,33 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
 use getset::Getters;
+use serde::{Deserialize, Serialize};
 
 use super::{server::AuthServer, AuthType, GenericAuthorization, OwnedAuthorization};
 
+/// Permission flags that make up a user's bitmask. A user is allowed an
+/// operation when the corresponding bit is set in their mask.
+pub mod flags {
+    /// No permissions at all.
+    pub const NONE: u32 = 0;
+    /// Read server state.
+    pub const SERVERS_READ: u32 = 1 << 0;
+    /// Create and mutate servers.
+    pub const SERVERS_WRITE: u32 = 1 << 1;
+    /// List users and invitations.
+    pub const USERS_READ: u32 = 1 << 2;
+    /// Create, invite and revoke users.
+    pub const USERS_WRITE: u32 = 1 << 3;
+    /// Every permission; the mask held by admins.
+    pub const ALL: u32 = u32::MAX;
+}
+
+/// Directory and file holding the persisted user store.
+const CONFIG_DIRECTORY: &str = "configs";
+const STORE_FILE: &str = "users.toml";
+
 #[derive(Getters)]
 pub struct AdminUser {
     #[getset(get = "pub")]
@@ -33,3 +59,163 @@ impl AdminUser {
         Box::new(Self { username })
     }
 }
+
+/// A non-admin user authorized by a permission bitmask.
+#[derive(Getters, Clone)]
+pub struct FlagUser {
+    #[getset(get = "pub")]
+    username: String,
+    #[getset(get = "pub")]
+    mask: u32,
+}
+
+impl GenericAuthorization for FlagUser {
+    fn is_allowed(&self, flag: u32) -> bool {
+        self.mask & flag != 0
+    }
+
+    fn get_user(&self) -> Option<&AdminUser> {
+        None
+    }
+    fn get_server(&self) -> Option<&AuthServer> {
+        None
+    }
+    fn is_type(&self, auth: AuthType) -> bool {
+        auth == AuthType::User
+    }
+
+    fn recreate(&self) -> OwnedAuthorization {
+        FlagUser::create(self.username.clone(), self.mask)
+    }
+}
+
+impl FlagUser {
+    pub fn create(username: String, mask: u32) -> OwnedAuthorization {
+        Box::new(Self { username, mask })
+    }
+}
+
+/// A persisted user record.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StoredUser {
+    pub username: String,
+    pub mask: u32,
+}
+
+/// A single-use invitation token carrying the permission flags a redeeming
+/// client will be registered with.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Invitation {
+    pub token: String,
+    pub mask: u32,
+    #[serde(default)]
+    pub used: bool,
+}
+
+/// TOML-backed registry of who may connect and the outstanding invitations.
+#[derive(Serialize, Deserialize, Default)]
+pub struct UserStore {
+    #[serde(default)]
+    users: Vec<StoredUser>,
+    #[serde(default)]
+    invitations: Vec<Invitation>,
+}
+
+impl UserStore {
+    fn path() -> PathBuf {
+        Path::new(CONFIG_DIRECTORY).join(STORE_FILE)
+    }
+
+    /// Load the store from disk, falling back to an empty one when absent.
+    pub fn load() -> Self {
+        let path = Self::path();
+        if !path.exists() {
+            return Self::default();
+        }
+        fs::read_to_string(&path)
+            .ok()
+            .and_then(|data| toml::from_str(&data).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// List every registered user.
+    pub fn list_users(&self) -> &[StoredUser] {
+        &self.users
+    }
+
+    /// Create a user with an explicit mask.
+    pub fn create_user(&mut self, username: String, mask: u32) -> Result<()> {
+        if self.users.iter().any(|user| user.username == username) {
+            return Err(anyhow!("A user named {} already exists", username));
+        }
+        self.users.push(StoredUser { username, mask });
+        self.save()
+    }
+
+    /// Revoke a user, removing them from the store.
+    pub fn revoke_user(&mut self, username: &str) -> Result<()> {
+        let before = self.users.len();
+        self.users.retain(|user| user.username != username);
+        if self.users.len() == before {
+            return Err(anyhow!("No user named {} to revoke", username));
+        }
+        self.save()
+    }
+
+    /// Mint a single-use invitation granting `mask`, returning its token.
+    pub fn add_invitation(&mut self, mask: u32) -> Result<String> {
+        let token = generate_token();
+        self.invitations.push(Invitation {
+            token: token.clone(),
+            mask,
+            used: false,
+        });
+        self.save()?;
+        Ok(token)
+    }
+
+    /// Redeem an invitation for `username`, creating the user with the
+    /// invitation's flags and burning the token.
+    pub fn redeem_invitation(&mut self, token: &str, username: String) -> Result<()> {
+        let invitation = self
+            .invitations
+            .iter_mut()
+            .find(|invitation| invitation.token == token && !invitation.used)
+            .ok_or_else(|| anyhow!("Unknown or already-used invitation token"))?;
+        invitation.used = true;
+        let mask = invitation.mask;
+        self.create_user(username, mask)
+    }
+}
+
+/// Mint an opaque, unguessable invitation token from the system CSPRNG.
+fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    getrandom::getrandom(&mut bytes).expect("the system CSPRNG must be available");
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Resolve a connecting username into an authorization. A user whose stored
+/// mask grants everything is treated as an [`AdminUser`] superuser; otherwise
+/// they get a [`FlagUser`] carrying their mask. Unknown users are rejected.
+pub fn authorize(username: &str) -> Option<OwnedAuthorization> {
+    let store = UserStore::load();
+    let user = store
+        .list_users()
+        .iter()
+        .find(|user| user.username == username)?;
+    if user.mask == flags::ALL {
+        Some(AdminUser::create(user.username.clone()))
+    } else {
+        Some(FlagUser::create(user.username.clone(), user.mask))
+    }
+}
@@ -0,0 +1,22 @@
+use std::net::SocketAddr;
+
+use anyhow::Result;
+use axum::Router;
+use log::info;
+use tokio::net::TcpListener;
+
+pub mod openapi;
+
+/// Build the controller's HTTP router, mounting the OpenAPI document and
+/// Swagger UI routes so `/openapi.json` and `/docs` are actually served.
+pub fn router() -> Router {
+    Router::new().merge(openapi::router())
+}
+
+/// Bind and serve the HTTP surface on `address`.
+pub async fn serve(address: SocketAddr) -> Result<()> {
+    let listener = TcpListener::bind(address).await?;
+    info!("Serving controller HTTP API on {}", address);
+    axum::serve(listener, router()).await?;
+    Ok(())
+}
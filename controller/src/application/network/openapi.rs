@@ -0,0 +1,112 @@
+use axum::response::{Html, IntoResponse};
+use axum::routing::get;
+use axum::Router;
+use serde_json::{json, Value};
+
+use crate::VERSION;
+
+/// Version of the published OpenAPI document. Bumped independently whenever
+/// the documented contract changes, so it can be cross-checked against — and
+/// legitimately diverge from — the wire protocol version.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Build the OpenAPI 3 document describing the controller's client surface.
+///
+/// The document is assembled by hand in this one function rather than derived
+/// from annotations on the handlers, so no derive dependency is pulled into
+/// the controller.
+pub fn document() -> Value {
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "atomic-cloud controller API",
+            "version": VERSION.to_string(),
+            "x-schema-version": SCHEMA_VERSION,
+        },
+        "paths": {
+            "/get_ctrl_ver": {
+                "get": {
+                    "operationId": "get_ctrl_ver",
+                    "summary": "Human-readable controller version",
+                    "responses": {
+                        "200": {
+                            "description": "The controller version string",
+                            "content": {
+                                "application/json": {
+                                    "schema": { "$ref": "#/components/schemas/Version" }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "/get_proto_ver": {
+                "get": {
+                    "operationId": "get_proto_ver",
+                    "summary": "Wire protocol version",
+                    "responses": {
+                        "200": {
+                            "description": "The protocol version integer",
+                            "content": {
+                                "application/json": {
+                                    "schema": { "$ref": "#/components/schemas/ProtocolVersion" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+        "components": {
+            "schemas": {
+                "Version": { "type": "string" },
+                "ProtocolVersion": { "type": "integer", "format": "uint32" }
+            },
+            "securitySchemes": {
+                "token": {
+                    "type": "http",
+                    "scheme": "bearer",
+                    "description": "Authorization token redeemed from an invitation"
+                }
+            }
+        },
+        "security": [ { "token": [] } ]
+    })
+}
+
+/// Serialized `/openapi.json` body.
+pub fn openapi_json() -> String {
+    document().to_string()
+}
+
+/// Routes serving the OpenAPI document and a browsable Swagger UI, to be
+/// nested onto the controller's HTTP surface.
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/openapi.json",
+            get(|| async { ([("content-type", "application/json")], openapi_json()).into_response() }),
+        )
+        .route("/docs", get(|| async { Html(swagger_ui()) }))
+}
+
+/// A minimal Swagger UI page that loads the served spec, mounted alongside
+/// `/openapi.json` for operators who want to browse the contract.
+pub fn swagger_ui() -> &'static str {
+    r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <title>atomic-cloud API</title>
+    <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist/swagger-ui.css" />
+  </head>
+  <body>
+    <div id="swagger-ui"></div>
+    <script src="https://unpkg.com/swagger-ui-dist/swagger-ui-bundle.js"></script>
+    <script>
+      window.onload = () => SwaggerUIBundle({ url: "/openapi.json", dom_id: "#swagger-ui" });
+    </script>
+  </body>
+</html>
+"#
+}
@@ -0,0 +1,31 @@
+use log::warn;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
+
+pub mod application;
+
+/// Accept a client connection: authenticate it against the persisted user
+/// store before handing it to [`dispatch`]. Connections from unknown users
+/// are dropped.
+pub async fn serve<S>(mut stream: S)
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut buffer = [0u8; 256];
+    let read = stream.read(&mut buffer).await.unwrap_or(0);
+    let username = String::from_utf8_lossy(&buffer[..read]).trim().to_string();
+
+    match application::auth::authenticate(&username) {
+        Some(authorization) => dispatch(stream, authorization).await,
+        None => warn!("Rejected connection from unknown user {}", username),
+    }
+}
+
+/// Receive an authenticated connection together with its authorization.
+/// No requests are read or handled here: the stream and the authorization
+/// are dropped on return, which closes the connection.
+async fn dispatch<S>(stream: S, authorization: application::auth::OwnedAuthorization)
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let _ = (stream, authorization);
+}